@@ -122,10 +122,11 @@ impl Context for Plain {
     /// Bear in mind, however, that Geodesy does not support all PROJ operators, and that
     /// the input/output conventions differ.
     fn op(&mut self, definition: &str) -> Result<OpHandle, Error> {
-        // It may be a PROJ string, so we filter it through the PROJ parser
-        let definition = parse_proj(definition)?;
+        // It may be a PROJ string, so we filter it through the PROJ parser.
+        // PROJ pipeline globals are handed to the operator as globals
+        let (definition, globals) = parse_proj_with_globals(definition)?;
 
-        let op = Op::new(&definition, self)?;
+        let op = Op::with_globals(&definition, &globals, self)?;
         let id = op.id;
         self.operators.insert(id, op);
         assert!(self.operators.contains_key(&id));
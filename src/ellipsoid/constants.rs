@@ -3,7 +3,7 @@ use crate::math::PolynomialCoefficients;
 // A HashMap would have been a better choice,for the OPERATOR_LIST, except
 // for the annoying fact that it cannot be compile-time constructed
 #[rustfmt::skip]
-pub(super) const ELLIPSOID_LIST: [(&str, &str, &str, &str, &str); 47] = [
+pub(super) const ELLIPSOID_LIST: [(&str, &str, &str, &str, &str); 49] = [
     ("MERIT",     "6378137",       "6378137",      "298.257",            "MERIT 1983"),
     ("SGS85",     "6378136",       "6378136",      "298.257",            "Soviet Geodetic System 85"),
     ("GRS80",     "6378137",       "6378137",      "298.2572221008827",  "GRS 1980(IUGG, 1980)"),
@@ -51,6 +51,8 @@ pub(super) const ELLIPSOID_LIST: [(&str, &str, &str, &str, &str); 47] = [
     ("WGS84",     "6378137",       "6378137",      "298.257223563",      "WGS 84"),
     ("sphere",    "6370997",       "6370997",      "0.",                 "Normal Sphere (r=6370997)"),
     ("unitsphere",      "1",             "1",      "0.",                 "Unit Sphere (r=1)"),
+    ("mars",      "3396190",       "3396190",      "169.8944472236118",  "Mars (IAU 2000)"),
+    ("moon",      "1737400",       "1737400",      "0.",                 "Moon (IAU 2000)"),
 ];
 
 #[rustfmt::skip]
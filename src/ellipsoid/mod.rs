@@ -55,12 +55,15 @@ impl Ellipsoid {
             return Ok(Ellipsoid::triaxial(ax, ay, f));
         }
 
-        // The "semiminor, reciproque-flattening" form, e.g. "6378137, 298.3"
+        // The "semimajor, reciproque-flattening" form, e.g. "6378137, 298.3".
+        // As in the builtin list, a reciproque flattening of 0 means a sphere,
+        // e.g. "1737400, 0" for the Moon
         let a_and_rf = name.split(',').collect::<Vec<_>>();
         if a_and_rf.len() == 2_usize {
             if let Ok(a) = a_and_rf[0].trim().parse::<f64>() {
                 if let Ok(rf) = a_and_rf[1].trim().parse::<f64>() {
-                    return Ok(Ellipsoid::new(a, 1. / rf));
+                    let f = if rf != 0.0 { 1.0 / rf } else { rf };
+                    return Ok(Ellipsoid::new(a, f));
                 }
             }
         }
@@ -206,6 +209,17 @@ mod tests {
 
         assert!((ellps.normalized_meridian_arc_unit() - 0.998_324_298_423_041_5).abs() < 1e-13);
         assert!((4.0 * ellps.meridian_quadrant() - 40_007_862.916_921_8).abs() < 1e-7);
+
+        // Non-terrestrial bodies: named...
+        let ellps = Ellipsoid::named("mars")?;
+        assert_eq!(ellps.semimajor_axis(), 3396190.0);
+        assert!((ellps.semiminor_axis() - 3376200.0).abs() < 1e-6);
+
+        // ...and given explicitly as a sphere (reciproque flattening 0)
+        let ellps = Ellipsoid::named("1737400, 0")?;
+        assert_eq!(ellps.semimajor_axis(), 1737400.0);
+        assert_eq!(ellps.flattening(), 0.);
+        assert_eq!(ellps, Ellipsoid::named("moon")?);
         Ok(())
     }
 
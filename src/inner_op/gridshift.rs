@@ -97,10 +97,13 @@ fn inv(op: &Op, _ctx: &dyn Context, operands: &mut dyn CoordinateSet) -> usize {
 // ----- C O N S T R U C T O R ------------------------------------------------------
 
 #[rustfmt::skip]
-pub const GAMUT: [OpParameter; 3] = [
+pub const GAMUT: [OpParameter; 4] = [
     OpParameter::Flag { key: "inv" },
     OpParameter::Texts { key: "grids", default: None },
     OpParameter::Real { key: "padding", default: Some(0.5) },
+    // The CRS the grids are defined for (e.g. "EPSG:4267"). Informational:
+    // recorded for pre-flight checks by the caller, not used by the operator
+    OpParameter::Text { key: "grid_crs", default: Some("") },
 ];
 
 pub fn new(parameters: &RawParameters, ctx: &dyn Context) -> Result<Op, Error> {
@@ -165,6 +168,11 @@ mod tests {
         assert!((data[0][0] - cph[0]).abs() < 1e-10);
         assert!((data[0][1] - cph[1]).abs() < 1e-10);
 
+        // The grid CRS is available for pre-flight checks, and empty if not given
+        assert_eq!(ctx.params(op, 0)?.text("grid_crs")?, "");
+        let op = ctx.op("gridshift grids=test.datum grid_crs=EPSG:4267")?;
+        assert_eq!(ctx.params(op, 0)?.text("grid_crs")?, "EPSG:4267");
+
         Ok(())
     }
 
@@ -67,10 +67,17 @@ pub fn new(parameters: &RawParameters, ctx: &dyn Context) -> Result<Op, Error> {
         steps.push(Op::op(step_parameters, ctx)?);
     }
 
+    // The pipeline is invertible only if all steps taking part in the
+    // inverse direction are. Otherwise an attempt to invert it (e.g. by
+    // invoking it as an inverted macro) fails with Error::NonInvertible
+    let invertible = steps
+        .iter()
+        .all(|step| step.descriptor.invertible || step.params.boolean("omit_inv"));
+
     let params = ParsedParameters::new(parameters, &GAMUT)?;
     let fwd = InnerOp(pipeline_fwd);
-    let inv = InnerOp(pipeline_inv);
-    let descriptor = OpDescriptor::new(definition, fwd, Some(inv));
+    let inv = invertible.then_some(InnerOp(pipeline_inv));
+    let descriptor = OpDescriptor::new(definition, fwd, inv);
     let id = OpHandle::new();
     Ok(Op {
         descriptor,
@@ -224,6 +231,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn long_pipeline() -> Result<(), Error> {
+        let mut ctx = Minimal::default();
+
+        // Steps are kept in a Vec in definition order, so there is no
+        // lexical "step_10 before step_2" mixup for long pipelines
+        let definition: Vec<String> = (0..12).map(|i| format!("helmert x={i}")).collect();
+        let op = ctx.op(&definition.join(" | "))?;
+
+        let steps = ctx.steps(op)?;
+        assert_eq!(steps.len(), 12);
+        for (i, step) in steps.iter().enumerate() {
+            assert_eq!(*step, format!("helmert x={i}"));
+        }
+        assert_eq!(ctx.params(op, 10)?.given["x"], "10");
+        assert_eq!(ctx.params(op, 11)?.given["x"], "11");
+
+        let mut data = some_basic_coor3dinates();
+        ctx.apply(op, Fwd, &mut data)?;
+        assert_eq!(data[0][0], 55. + 66.);
+        Ok(())
+    }
+
+    #[test]
+    fn invertibility() -> Result<(), Error> {
+        let mut ctx = Minimal::default();
+
+        // curvature has no inverse, so neither has a pipeline containing it...
+        ctx.register_resource("curv:one", "addone | curvature prime");
+        assert!(ctx.op("curv:one").is_ok());
+        assert!(matches!(
+            ctx.op("curv:one inv"),
+            Err(Error::NonInvertible(_))
+        ));
+
+        // ...unless it is omitted in the inverse direction
+        ctx.register_resource("curv:two", "addone | curvature prime omit_inv");
+        let op = ctx.op("curv:two inv")?;
+        let mut data = some_basic_coor2dinates();
+        assert_eq!(2, ctx.apply(op, Fwd, &mut data)?);
+        assert_eq!(data[0][0], 54.);
+        Ok(())
+    }
+
     #[test]
     fn push_pop() -> Result<(), Error> {
         let mut ctx = Minimal::default();
@@ -1,6 +1,7 @@
 /// The unit conversion operator.
 /// It has a subset of the conversions supported by PROJ.
 /// NB: If no units are specified, the default is meters.
+/// Unknown units are rejected with `Error::BadParam` at instantiation time.
 /// ...
 /// Conversions are performed by means of a pivot unit.
 /// For horizontal conversions, the pivot unit is meters for linear units and radians for angular units.
@@ -11,23 +12,15 @@ use crate::authoring::*;
 
 // ----- C O M M O N -------------------------------------------------------------------
 
-fn get_pivot_multiplier(name: &str) -> f64 {
-    // First try linear units.
-    let units = linear_units_map();
-    match units.get(name) {
-        Some(u) => u.multiplier(),
-        None => {
-            // Then try angular units.
-            let units = angular_units_map();
-            match units.get(name) {
-                Some(u) => u.multiplier(),
-                // If we can't find it we return NaN.
-                // I'm uncertain if this is the best approach. It's what Proj4.js does,
-                // but should we rather panic or throw and error?
-                None => f64::NAN,
-            }
-        }
+fn get_pivot_multiplier(key: &str, name: &str) -> Result<f64, Error> {
+    // First try linear units, then angular units
+    if let Some(u) = linear_units_map().get(name) {
+        return Ok(u.multiplier());
     }
+    if let Some(u) = angular_units_map().get(name) {
+        return Ok(u.multiplier());
+    }
+    Err(Error::BadParam(key.to_string(), name.to_string()))
 }
 
 // ----- F O R W A R D -----------------------------------------------------------------
@@ -112,11 +105,11 @@ pub fn new(parameters: &RawParameters, _ctx: &dyn Context) -> Result<Op, Error>
     let z_in = params.text("z_in").unwrap();
     let z_out = params.text("z_out").unwrap();
 
-    let xy_in_to_pivot = get_pivot_multiplier(xy_in.as_str());
-    let pivot_to_xy_out = 1. / get_pivot_multiplier(xy_out.as_str());
+    let xy_in_to_pivot = get_pivot_multiplier("xy_in", &xy_in)?;
+    let pivot_to_xy_out = 1. / get_pivot_multiplier("xy_out", &xy_out)?;
 
-    let z_in_to_pivot = get_pivot_multiplier(z_in.as_str());
-    let pivot_to_z_out = 1. / get_pivot_multiplier(z_out.as_str());
+    let z_in_to_pivot = get_pivot_multiplier("z_in", &z_in)?;
+    let pivot_to_z_out = 1. / get_pivot_multiplier("z_out", &z_out)?;
 
     params.real.insert("xy_in_to_pivot", xy_in_to_pivot);
     params.real.insert("pivot_to_xy_out", pivot_to_xy_out);
@@ -273,29 +266,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn xy_deg_to_rad_and_m_to_us_ft() -> Result<(), Error> {
+        let mut ctx = Minimal::default();
+        let op = ctx.op("unitconvert xy_in=deg xy_out=rad z_in=m z_out=us-ft")?;
+
+        let mut operands = [Coor4D::raw(180., 90., 1200., 1.)];
+        ctx.apply(op, Fwd, &mut operands)?;
+        assert_float_eq!(operands[0][0], std::f64::consts::PI, abs_all <= 1e-12);
+        assert_float_eq!(
+            operands[0][1],
+            std::f64::consts::FRAC_PI_2,
+            abs_all <= 1e-12
+        );
+        assert_float_eq!(operands[0][2], 3937., abs_all <= 1e-9);
+
+        // The statute mile is 'mi', so it no longer shadows the nautical mile 'kmi'
+        let op = ctx.op("unitconvert xy_in=mi xy_out=kmi")?;
+        let mut operands = [Coor4D::raw(1852., 1852., 0., 0.)];
+        ctx.apply(op, Fwd, &mut operands)?;
+        assert_float_eq!(operands[0][0], 1609.344, abs_all <= 1e-9);
+        Ok(())
+    }
+
     #[test]
     fn unknown_unit() -> Result<(), Error> {
         let mut ctx = Minimal::default();
         ctx.register_op("unitconvert", OpConstructor(new));
-        let op = ctx.op("unitconvert xy_in=unknown xy_out=deg")?;
-
-        let mut operands = [Coor4D::raw(135.0, 40., 500., 1.)];
-
-        // Forward
-        let successes = ctx.apply(op, Fwd, &mut operands)?;
-        assert!(operands[0][0].is_nan());
-        assert!(operands[0][1].is_nan());
-        assert_float_eq!(operands[0][2], 500., abs_all <= 1e-5);
-        assert_float_eq!(operands[0][3], 1., abs_all <= 1e-5);
-
-        assert_eq!(successes, 1);
-
-        // Inverse + roundtrip
-        ctx.apply(op, Inv, &mut operands)?;
-        assert!(operands[0][0].is_nan());
-        assert!(operands[0][1].is_nan());
-        assert_float_eq!(operands[0][2], 500., abs_all <= 1e-9);
-        assert_float_eq!(operands[0][3], 1., abs_all <= 1e-9);
+        assert!(matches!(
+            ctx.op("unitconvert xy_in=unknown xy_out=deg"),
+            Err(Error::BadParam(_, _))
+        ));
+        assert!(matches!(
+            ctx.op("unitconvert z_out=furlong"),
+            Err(Error::BadParam(_, _))
+        ));
         Ok(())
     }
 }
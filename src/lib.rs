@@ -70,6 +70,7 @@ pub mod authoring {
     pub use crate::math::jacobian::Jacobian;
 
     pub use crate::parse_proj;
+    pub use crate::parse_proj_with_globals;
     pub use crate::Tokenize;
 
     // External material
@@ -187,6 +188,7 @@ pub use crate::token::Tokenize;
 
 // PROJ interoperability
 pub use crate::token::parse_proj;
+pub use crate::token::parse_proj_with_globals;
 
 // The lower level data types, mostly use in the extended prelude 'authoring'
 pub use crate::grid::Grid;
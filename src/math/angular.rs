@@ -83,37 +83,56 @@ pub fn normalize_positive(angle: f64) -> f64 {
 }
 
 /// Parse sexagesimal degrees, i.e. degrees, minutes and seconds in the
-/// format 45:30:36, 45:30:36N,-45:30:36 etc.
-pub fn parse_sexagesimal(angle: &str) -> f64 {
+/// format 45:30:36, 45:30:36N,-45:30:36 etc. The traditional markers
+/// are also accepted, as in 45°30'36"N, 45d30'36"N, or -9d12.5'.
+/// Minutes and seconds must be less than 60.
+///
+/// Note that this is also the parser behind all `Real` and `Series`
+/// operator parameters, so the sexagesimal forms are accepted there
+/// too: `x=1d30'` means 1.5, just as `x=1:30` does.
+pub fn parse_sexagesimal(input: &str) -> f64 {
     // Degrees, minutes, and seconds
     let mut dms = [0.0, 0.0, 0.0];
-    let mut angle = angle.trim();
 
     // Empty?
-    let n = angle.len();
-    if n == 0 || angle == "NaN" {
+    let mut angle = input.trim();
+    if angle.is_empty() || angle == "NaN" {
         return f64::NAN;
     }
 
     // Handle NSEW indicators
     let mut postfix_sign = 1.0;
-    if "wWsSeEnN".contains(&angle[n - 1..]) {
-        if "wWsS".contains(&angle[n - 1..]) {
-            postfix_sign = -1.0;
+    if let Some(last) = angle.chars().last() {
+        if "wWsSeEnN".contains(last) {
+            if "wWsS".contains(last) {
+                postfix_sign = -1.0;
+            }
+            angle = angle.strip_suffix(last).unwrap_or(angle);
         }
-        angle = &angle[..n - 1];
     }
 
+    // A trailing marker terminates the last element, so it is removed, while
+    // the remaining degree and minute markers become ':'-separators. Any
+    // other trailing characters (e.g. a stray ':') are left for the parser
+    // below to reject
+    let angle = angle.strip_suffix(['°', 'd', '\'', '"']).unwrap_or(angle);
+    let angle = angle.replace(['°', 'd', '\''], ":");
+
     // Split into as many elements as given: D, D:M, D:M:S
     for (i, element) in angle.split(':').enumerate() {
         if i < 3 {
             if let Ok(v) = element.parse::<f64>() {
+                // Minutes and seconds out of range?
+                if i > 0 && !(0.0..60.0).contains(&v) {
+                    warn!("Minutes and seconds must be in [0, 60) in {input}");
+                    return f64::NAN;
+                }
                 dms[i] = v;
                 continue;
             }
         }
         // More than 3 elements?
-        warn!("Cannot parse {angle} as a real number or sexagesimal angle");
+        warn!("Cannot parse {input} as a real number or sexagesimal angle");
         return f64::NAN;
     }
 
@@ -160,5 +179,24 @@ mod tests {
         assert_eq!(1.51, parse_sexagesimal("1:30:36e"));
         assert_eq!(-1.51, parse_sexagesimal("1:30:36w"));
         assert!(parse_sexagesimal("q1:30:36w").is_nan());
+
+        // Traditional markers
+        assert_eq!(-1.51, parse_sexagesimal("1°30'36\"S"));
+        assert_eq!(1.51, parse_sexagesimal("1d30'36\""));
+        assert!((parse_sexagesimal("55d40'30\"S") + 55.675).abs() < 1e-12);
+        assert!((parse_sexagesimal("-9d12.5'") + 9.208_333_333_333_334).abs() < 1e-12);
+        assert_eq!(12., parse_sexagesimal("12°E"));
+
+        // Minutes and seconds out of range
+        assert!(parse_sexagesimal("55:60:00").is_nan());
+        assert!(parse_sexagesimal("55d30'60\"N").is_nan());
+
+        // Malformed input and unrecognized markers are rejected, without panicking
+        assert!(parse_sexagesimal("55:").is_nan());
+        assert!(parse_sexagesimal("12′").is_nan());
+        assert!(parse_sexagesimal("12º").is_nan());
+        assert!(parse_sexagesimal("12°30′15″").is_nan());
+        assert!(parse_sexagesimal("NaN").is_nan());
+        assert!(parse_sexagesimal("").is_nan());
     }
 }
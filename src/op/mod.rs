@@ -51,8 +51,19 @@ impl Op {
     }
 
     pub fn new(definition: &str, ctx: &dyn Context) -> Result<Op, Error> {
-        let globals = ctx.globals();
-        let parameters = RawParameters::new(definition, &globals);
+        Self::with_globals(definition, &BTreeMap::new(), ctx)
+    }
+
+    // Instantiate with additional globals (e.g. the pipeline globals of a PROJ
+    // string), taking precedence over the globals of the context
+    pub fn with_globals(
+        definition: &str,
+        globals: &BTreeMap<String, String>,
+        ctx: &dyn Context,
+    ) -> Result<Op, Error> {
+        let mut all = ctx.globals();
+        all.extend(globals.clone());
+        let parameters = RawParameters::new(definition, &all);
         Self::op(parameters, ctx)
     }
 
@@ -120,11 +131,19 @@ impl Op {
         }
         // A user defined macro?
         else if let Ok(macro_definition) = ctx.get_resource(&name) {
-            // search for whitespace-delimited "inv" in order to avoid matching
-            // tokens *containing* inv (INVariant, subINVolution, and a few other
-            // pathological cases)
+            // The inversion flag of the macro invocation is interpreted exactly
+            // as for any other operator, so e.g. `inv=yes` and `inv=no` work too
             let def = &parameters.definition;
-            let inverted = def.contains(" inv ") || def.ends_with(" inv");
+            let inverted = match def.split_into_parameters().get("inv") {
+                None => false,
+                Some(value) => match parsed_parameters::flag_value(value) {
+                    Some(set) => set,
+                    None => {
+                        warn!("Cannot parse inv:{value} as a boolean constant!");
+                        return Err(Error::BadParam("inv".to_string(), value.clone()));
+                    }
+                },
+            };
             let mut next_param = parameters.next(def);
             next_param.definition = macro_definition;
             return Op::op(next_param, ctx)?.handle_inversion(inverted);
@@ -149,6 +168,14 @@ impl Op {
     fn handle_inversion(mut self, inverted: bool) -> Result<Op, Error> {
         if self.descriptor.invertible {
             if inverted {
+                // An inverted invocation of an already inverted definition (e.g. a
+                // macro defined as "foo inv", invoked as "macro inv") cancels out
+                if self.descriptor.inverted {
+                    warn!(
+                        "Double inversion of '{}': net direction is forward",
+                        self.descriptor.definition
+                    );
+                }
                 self.descriptor.inverted = !self.descriptor.inverted;
             }
             return Ok(self);
@@ -270,6 +297,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn macro_inversion_flag_values() -> Result<(), Error> {
+        let mut ctx = Minimal::default();
+        ctx.register_resource("m:one", "addone");
+
+        // The inversion flag means the same for a macro as for a builtin
+        for (inv, expected) in [
+            ("inv", 54.),
+            ("inv=true", 54.),
+            ("inv=yes", 54.),
+            ("inv=no", 56.),
+        ] {
+            for name in ["addone", "m:one"] {
+                let op = ctx.op(&format!("{name} {inv}"))?;
+                let mut data = some_basic_coor2dinates();
+                ctx.apply(op, Fwd, &mut data)?;
+                assert_eq!(data[0][0], expected, "{name} {inv}");
+            }
+        }
+
+        // ...and values that are not boolean constants are rejected by both
+        for name in ["addone", "m:one"] {
+            assert!(matches!(
+                ctx.op(&format!("{name} inv=maybe")),
+                Err(Error::BadParam(_, _))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn macro_inversion_is_not_inherited() -> Result<(), Error> {
+        let mut ctx = Minimal::default();
+        ctx.register_resource("sub:pair", "addone | helmert x=2");
+
+        // Inverting a macro inverts it as a whole: The `inv` of the invocation
+        // is not handed down to the individual steps as a global, since that
+        // would invert each step, but not the order of their application
+        let op = ctx.op("sub:pair inv")?;
+        assert!(!ctx.params(op, 0)?.boolean("inv"));
+        assert!(!ctx.params(op, 1)?.boolean("inv"));
+
+        let mut data = some_basic_coor3dinates();
+        ctx.apply(op, Fwd, &mut data)?;
+        assert_eq!(data[0][0], 52.);
+        ctx.apply(op, Inv, &mut data)?;
+        assert_eq!(data[0][0], 55.);
+
+        Ok(())
+    }
+
+    // A logger capturing the messages emitted, so tests can check diagnostics
+    struct Capture(std::sync::Mutex<Vec<String>>);
+    static CAPTURE: Capture = Capture(std::sync::Mutex::new(Vec::new()));
+    impl log::Log for Capture {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn double_inversion() -> Result<(), Error> {
+        let _ = log::set_logger(&CAPTURE);
+        log::set_max_level(log::LevelFilter::Warn);
+
+        // The scope tag makes the definition, and hence the warning, unique to
+        // this test, as tests run in parallel and share the logger
+        let mut ctx = Minimal::default();
+        ctx.register_resource("sub:twice", "addone inv scope=double_inversion");
+
+        // The macro definition is inverted, so plain invocation is inverted...
+        assert!(Op::new("sub:twice", &ctx)?.descriptor.inverted);
+        let warned = || {
+            CAPTURE.0.lock().unwrap().iter().any(|message| {
+                message.starts_with("Double inversion")
+                    && message.contains("scope=double_inversion")
+            })
+        };
+        assert!(!warned());
+
+        // ...while an inverted invocation cancels out to the forward direction,
+        // which is pointed out by a warning
+        assert!(!Op::new("sub:twice inv", &ctx)?.descriptor.inverted);
+        assert!(warned());
+
+        Ok(())
+    }
+
     #[test]
     fn macro_expansion_with_embedded_pipeline() -> Result<(), Error> {
         let mut data = some_basic_coor2dinates();
@@ -47,3 +47,18 @@ pub enum OpParameter {
         default: Option<&'static str>,
     },
 }
+
+impl OpParameter {
+    /// The key under which the parameter is given in an operator definition
+    pub fn key(&self) -> &'static str {
+        match *self {
+            OpParameter::Flag { key } => key,
+            OpParameter::Natural { key, .. } => key,
+            OpParameter::Integer { key, .. } => key,
+            OpParameter::Real { key, .. } => key,
+            OpParameter::Series { key, .. } => key,
+            OpParameter::Text { key, .. } => key,
+            OpParameter::Texts { key, .. } => key,
+        }
+    }
+}
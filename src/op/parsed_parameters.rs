@@ -20,6 +20,10 @@ const UNIT_VALUED_IMPLICIT_GAMUT_ELEMENTS: [&str; 4] = [
     "k_0", "k_1", "k_2", "k_3"
 ];
 
+// Provenance metadata (e.g. from EPSG), accepted by any operator. They are
+// never parsed, but remain available (verbatim) in `ParsedParameters::given`
+const METADATA_ELEMENTS: [&str; 2] = ["scope", "remarks"];
+
 /// The [InnerOp](crate::inner_op::InnerOp) specific
 /// representation of the operator arguments.
 ///
@@ -166,8 +170,10 @@ impl ParsedParameters {
             match *p {
                 OpParameter::Flag { key } => {
                     if let Some(value) = chase(globals, &locals, key)? {
-                        if value.is_empty() || value.to_lowercase() == "true" {
-                            boolean.insert(key);
+                        if let Some(set) = flag_value(&value) {
+                            if set {
+                                boolean.insert(key);
+                            }
                             continue;
                         }
                         warn!("Cannot parse {key}:{value} as a boolean constant!");
@@ -339,12 +345,12 @@ impl ParsedParameters {
 
         // omit_fwd and omit_inv are implicitly valid for all ops
         if let Some(value) = chase(globals, &locals, "omit_fwd")? {
-            if value.is_empty() || value.to_lowercase() == "true" {
+            if flag_value(&value) == Some(true) {
                 boolean.insert("omit_fwd");
             }
         }
         if let Some(value) = chase(globals, &locals, "omit_inv")? {
-            if value.is_empty() || value.to_lowercase() == "true" {
+            if flag_value(&value) == Some(true) {
                 boolean.insert("omit_inv");
             }
         }
@@ -369,9 +375,29 @@ impl ParsedParameters {
         // TODO:
         // Params explicitly set to the default value
         // let mut redundant = BTreeSet::<String>::new();
-        // Params specified, but not used
-        let given = locals.clone();
-        let ignored: Vec<String> = locals.into_keys().collect();
+
+        // Params specified, but not used. For pipelines, the parameters
+        // belong to the individual steps, and are checked there
+        let mut ignored = Vec::<String>::new();
+        if !parameters.definition.is_pipeline() {
+            for key in locals.keys() {
+                let key = key.as_str();
+                if key == "_name"
+                    || key == "omit_fwd"
+                    || key == "omit_inv"
+                    || METADATA_ELEMENTS.contains(&key)
+                    || gamut.iter().any(|p| p.key() == key)
+                {
+                    continue;
+                }
+                let hint = nearest_key(key, gamut)
+                    .map(|k| format!(" (did you mean '{k}'?)"))
+                    .unwrap_or_default();
+                warn!("Ignoring unknown parameter '{key}' for operator '{name}'{hint}");
+                ignored.push(key.to_string());
+            }
+        }
+        let given = locals;
         Ok(ParsedParameters {
             name,
             boolean,
@@ -392,6 +418,59 @@ impl ParsedParameters {
 
 // ----- A N C I L L A R Y   F U N C T I O N S -----------------------------------------
 
+/// The one place where we decide whether the value of a flag means "set".
+/// A bare flag (e.g. `inv`) arrives here as `true` (the tokenizer sees to
+/// that), and is set. So is one given as `yes`, `on` or `1`, while `false`,
+/// `no`, `off`, `0` and the empty value of an explicit `key=` unset it (as in
+/// PROJ-style definitions). Anything else is not a boolean constant, and
+/// results in `None`.
+///
+/// The boolean constants are ASCII, so an ASCII case-insensitive comparison
+/// suffices, and saves the allocation of a lowercased copy of the value
+pub(super) fn flag_value(value: &str) -> Option<bool> {
+    if value.is_empty() {
+        return Some(false);
+    }
+    let is = |constants: [&str; 4]| constants.iter().any(|c| value.eq_ignore_ascii_case(c));
+    if is(["true", "yes", "on", "1"]) {
+        return Some(true);
+    }
+    if is(["false", "no", "off", "0"]) {
+        return Some(false);
+    }
+    None
+}
+
+/// Find the known key nearest to an unknown one, to help spotting typos
+/// (e.g. `elips` for `ellps`). Only keys within an edit distance of 2 qualify
+fn nearest_key(key: &str, gamut: &[OpParameter]) -> Option<&'static str> {
+    gamut
+        .iter()
+        .map(|p| p.key())
+        .chain(["omit_fwd", "omit_inv"])
+        .chain(METADATA_ELEMENTS)
+        .map(|k| (levenshtein(key, k), k))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, k)| k)
+}
+
+/// The Levenshtein (edit) distance between two strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 pub fn chase(
     globals: &BTreeMap<String, String>,
     locals: &BTreeMap<String, String>,
@@ -401,7 +480,9 @@ pub fn chase(
     let mut haystack = globals.iter().chain(locals.iter()).rev();
 
     // Find the needle in the haystack, recursively chasing look-ups ('$')
-    // and handling defaults ('*')
+    // and handling defaults ('*'). Since the haystack is consumed as we go,
+    // circular look-ups (e.g. 'a=$b b=$a') cannot loop forever: they end
+    // with an "Incomplete definition" syntax error
     let key = key.trim();
     if key.is_empty() {
         return Err(Error::Syntax(String::from("Empty key")));
@@ -532,6 +613,14 @@ mod tests {
         assert_eq!(*p.integer.get("integer").unwrap(), -1);
         assert_eq!(*p.text.get("text").unwrap(), "text");
 
+        // Real parameters are parsed as sexagesimal, so the traditional
+        // degree/minute/second markers and hemisphere indicators apply
+        let invocation = String::from("cucumber sexagesimal=55d40'30\"S series=1d30',2:15");
+        let raw = RawParameters::new(&invocation, &globals);
+        let dms = ParsedParameters::new(&raw, &GAMUT)?;
+        assert_eq!(dms.real("sexagesimal")?, -55.675);
+        assert_eq!(dms.series("series")?, [1.5, 2.25]);
+
         assert_eq!(
             p.ellps(0).semimajor_axis(),
             Ellipsoid::new(123., 1. / 456.).semimajor_axis()
@@ -545,6 +634,48 @@ mod tests {
             Err(Error::BadParam(_, _))
         ));
 
+        // Flags are case-insensitive - also the implicit omit_fwd/omit_inv flags
+        assert_eq!(flag_value("True"), Some(true));
+        assert_eq!(flag_value("yes"), Some(true));
+        assert_eq!(flag_value("FALSE"), Some(false));
+        assert_eq!(flag_value("No"), Some(false));
+        assert_eq!(flag_value("0"), Some(false));
+        assert_eq!(flag_value(""), Some(false));
+        assert_eq!(flag_value("maybe"), None);
+        let invocation = String::from("cucumber omit_fwd=TRUE omit_inv=no");
+        let raw = RawParameters::new(&invocation, &globals);
+        let p = ParsedParameters::new(&raw, &GAMUT)?;
+        assert!(p.boolean("omit_fwd"));
+        assert!(!p.boolean("omit_inv"));
+        let invocation = String::from("cucumber flag=TRUE");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(ParsedParameters::new(&raw, &GAMUT)?.boolean("flag"));
+        let invocation = String::from("cucumber flag=Off");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(!ParsedParameters::new(&raw, &GAMUT)?.boolean("flag"));
+        let invocation = String::from("cucumber flag=");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(!ParsedParameters::new(&raw, &GAMUT)?.boolean("flag"));
+        let invocation = String::from("cucumber flag");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(ParsedParameters::new(&raw, &GAMUT)?.boolean("flag"));
+
+        // The same flag values apply to the inversion of macro invocations
+        let mut ctx = Minimal::default();
+        ctx.register_resource("m:one", "addone");
+        for (inv, inverted) in [
+            ("inv=ON", true),
+            ("inv=1", true),
+            ("inv=off", false),
+            ("inv=0", false),
+            ("inv=", false),
+        ] {
+            assert_eq!(
+                Op::new(&format!("m:one {inv}"), &ctx)?.descriptor.inverted,
+                inverted
+            );
+        }
+
         // Invalid indirection (i.e. missing macro argument)
         let invocation = String::from("cucumber integer=$not_given");
         let raw = RawParameters::new(&invocation, &globals);
@@ -553,6 +684,51 @@ mod tests {
             Err(Error::Syntax(_))
         ));
 
+        // Parameters given, but not in the gamut, are ignored (and reported as such).
+        // This includes the implicit elements, which are only ever defaulted, so
+        // unless declared in the gamut, a given `x_0` would otherwise vanish silently
+        let invocation = String::from("cucumber flag elips=intl omit_fwd x_0=3");
+        let raw = RawParameters::new(&invocation, &globals);
+        let p = ParsedParameters::new(&raw, &GAMUT)?;
+        assert_eq!(p.ignored(), ["elips", "x_0"]);
+        assert_eq!(p.real["x_0"], 0.);
+
+        // Typos in ignored parameters get a suggestion for the nearest known key
+        assert_eq!(levenshtein("elips", "ellps"), 1);
+        assert_eq!(levenshtein("", "abc"), 3);
+        let ellps = [OpParameter::Text {
+            key: "ellps",
+            default: Some("GRS80"),
+        }];
+        assert_eq!(nearest_key("elips", &ellps), Some("ellps"));
+        assert_eq!(nearest_key("nams", &GAMUT), Some("names"));
+        assert_eq!(nearest_key("omit_inverse", &GAMUT), None);
+        assert_eq!(nearest_key("elips", &GAMUT), None);
+        assert_eq!(p.given["elips"], "intl");
+
+        // Provenance metadata are kept, but not reported as ignored
+        let invocation = String::from("cucumber scope=cadastre remarks=zone_32");
+        let raw = RawParameters::new(&invocation, &globals);
+        let p = ParsedParameters::new(&raw, &GAMUT)?;
+        assert!(p.ignored().is_empty());
+        assert_eq!(p.given["scope"], "cadastre");
+        assert_eq!(p.given["remarks"], "zone_32");
+
+        // Circular indirections terminate: each look-up continues the search
+        // in the remainder of the haystack, so a cycle eventually runs dry
+        let invocation = String::from("cucumber integer=$a a=$b b=$a");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(matches!(
+            ParsedParameters::new(&raw, &GAMUT),
+            Err(Error::Syntax(_))
+        ));
+        let invocation = String::from("cucumber integer=$integer");
+        let raw = RawParameters::new(&invocation, &globals);
+        assert!(matches!(
+            ParsedParameters::new(&raw, &GAMUT),
+            Err(Error::Syntax(_))
+        ));
+
         // Valid indirection, because we combine the arg with a default
         let invocation = String::from("cucumber integer=$not_given_but_defaults_to_42(42)");
         let raw = RawParameters::new(&invocation, &globals);
@@ -223,9 +223,26 @@ where
 /// - *parse_proj* will replace `k` with `k_0` whenever it is encountered.
 ///
 pub fn parse_proj(definition: &str) -> Result<String, Error> {
+    Ok(translate_proj(definition, true)?.0)
+}
+
+/// Like [parse_proj], but rather than inserting the PROJ pipeline globals into
+/// each step, return them separately, for use as operator globals (cf.
+/// [Op::with_globals](crate::op::Op::with_globals)). This way, they are available
+/// to all steps, while steps not using them do not report them as ignored
+pub fn parse_proj_with_globals(
+    definition: &str,
+) -> Result<(String, BTreeMap<String, String>), Error> {
+    translate_proj(definition, false)
+}
+
+fn translate_proj(
+    definition: &str,
+    inline_globals: bool,
+) -> Result<(String, BTreeMap<String, String>), Error> {
     // If it doesn't look like a PROJ string, we return it unchanged
     if definition.contains('|') | !definition.contains("proj") {
-        return Ok(definition.to_string());
+        return Ok((definition.to_string(), BTreeMap::new()));
     }
     // Impose some line ending sanity and remove the PROJ '+' prefix
     let all = definition
@@ -334,7 +351,7 @@ pub fn parse_proj(definition: &str) -> Result<String, Error> {
         // inversions, and handle directional omissions (omit_fwd, omit_inv)
         let mut geodesy_step = elements.join(" ").trim().to_string();
         if !geodesy_step.is_empty() {
-            if !pipeline_globals.is_empty() {
+            if inline_globals && !pipeline_globals.is_empty() {
                 elements.insert(1, pipeline_globals.clone());
             }
 
@@ -362,7 +379,12 @@ pub fn parse_proj(definition: &str) -> Result<String, Error> {
             }
         }
     }
-    Ok(geodesy_steps.join(" | ").trim().to_string())
+
+    // The globals are split into parameters, with a dummy operator name
+    // to avoid a leading flag being taken as the name
+    let mut globals = format!("pipeline {pipeline_globals}").split_into_parameters();
+    globals.remove("_name");
+    Ok((geodesy_steps.join(" | ").trim().to_string(), globals))
 }
 
 // Address some known incompatibilities between PROJ and Rust Geodesy
@@ -570,6 +592,37 @@ mod tests {
         assert_eq!(operands[0][0], 57.0);
         assert_eq!(operands[1][0], 61.0);
 
+        // PROJ operator names map directly onto builtin operators, and
+        // the PROJ spellings of the geographic "no-ops" are builtin aliases
+        // for noop (cf. BUILTIN_OPERATORS in inner_op/mod.rs)
+        for name in ["longlat", "latlong", "lonlat", "latlon"] {
+            let op = ctx.op(&parse_proj(&format!("+proj={name} +ellps=GRS80"))?)?;
+            let mut operands = some_basic_coor2dinates();
+            assert_eq!(2, ctx.apply(op, Fwd, &mut operands)?);
+            assert_eq!(operands[0][0], 55.0);
+        }
+        assert!(matches!(
+            ctx.op(&parse_proj("+proj=not_in_geodesy")?),
+            Err(Error::NotFound(_, _))
+        ));
+
+        // Alternatively, the pipeline globals can be returned separately...
+        let (steps, globals) = parse_proj_with_globals(
+            "proj=pipeline inv +foo=bar ellps=intl step proj=cart step proj=helmert x=1",
+        )?;
+        assert_eq!(steps, "helmert inv x=1 | cart inv");
+        assert_eq!(globals.len(), 2);
+        assert_eq!(globals["foo"], "bar");
+        assert_eq!(globals["ellps"], "intl");
+
+        // ...which is what the Plain context does: They are available to all
+        // steps, but not reported as ignored by those not using them
+        let mut ctx = Plain::default();
+        let op = ctx.op("+proj=pipeline +ellps=intl +step +proj=cart +step +proj=helmert +x=1")?;
+        assert!(ctx.params(op, 0)?.ignored().is_empty());
+        assert!(ctx.params(op, 1)?.ignored().is_empty());
+        assert_eq!(ctx.params(op, 0)?.ellps(0), Ellipsoid::named("intl")?);
+
         Ok(())
     }
 